@@ -94,3 +94,34 @@ fn _even_better_main() -> Result<()> {
 
     For the structured approach, check out the _thiserror directory.
 */
+
+use anyhow::Context;
+
+/*
+    anyhow's other headline feature is attaching human-readable context to an error as it
+    bubbles up, via .context() / .with_context(). Each call wraps the error in a new layer,
+    building a chain from the most specific cause to the most human-facing explanation.
+*/
+fn _read_instrs(path: &str) -> Result<String> {
+    std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read instrs from {path}"))
+}
+
+/*
+    anyhow::Error has three Display modes, picked by the format specifier:
+    - {}   prints only the outermost context message
+    - {:#} prints the whole chain on one line, as "msg: cause: cause: ..."
+    - {:?} prints the outermost message followed by a multi-line "Caused by: ..." list,
+           and a backtrace too if RUST_BACKTRACE=1 is set
+
+    Returning anyhow::Result from main uses this {:?} form automatically when the program
+    exits with an error, prefixed with "Error: ", so you get the full chain for free
+    without printing it yourself.
+*/
+fn _context_demo() {
+    if let Err(error) = _read_instrs("/not/there") {
+        println!("{}", error);
+        println!("{:#}", error);
+        println!("{:?}", error);
+    }
+}
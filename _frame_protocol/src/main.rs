@@ -0,0 +1,93 @@
+/*
+    When you hand-roll a binary wire format over a raw byte stream (e.g. a custom TCP
+    protocol), you can't rely on serde to parse it for you. Instead you slice into a
+    &[u8] by hand, and every slice access can fail if the input is shorter than expected.
+
+    A tempting shortcut is to use `?` directly on an Option, e.g. `bytes.get(i)?`.
+    That only works if the surrounding function returns Option, and even then it collapses
+    every possible failure into a single None, throwing away which field was missing.
+    The fix is to convert each Option into your own error type with .ok_or(...), so `?`
+    propagates a typed FrameError instead of silently returning None.
+*/
+
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Frame {
+    txflag: u8,
+    msgtype: u8,
+    sender: u8,
+    route_len: u8,
+    route: Vec<u8>,
+    payload: Vec<u8>,
+}
+
+#[derive(Error, Debug)]
+enum FrameError {
+    #[error("frame is too short to contain a header")]
+    TooShort,
+    #[error("route is truncated")]
+    TruncatedRoute,
+}
+
+impl Frame {
+    fn from_bytes(bytes: &[u8]) -> Result<Frame, FrameError> {
+        let txflag = *bytes.first().ok_or(FrameError::TooShort)?;
+        let msgtype = *bytes.get(1).ok_or(FrameError::TooShort)?;
+        let sender = *bytes.get(2).ok_or(FrameError::TooShort)?;
+        let route_len = *bytes.get(3).ok_or(FrameError::TooShort)?;
+
+        let route = bytes
+            .get(4..4 + route_len as usize)
+            .ok_or(FrameError::TruncatedRoute)?
+            .to_vec();
+        let payload = bytes[4 + route_len as usize..].to_vec();
+
+        Ok(Frame {
+            txflag,
+            msgtype,
+            sender,
+            route_len,
+            route,
+            payload,
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.txflag, self.msgtype, self.sender, self.route_len];
+        bytes.extend_from_slice(&self.route);
+        bytes.extend_from_slice(&self.payload);
+        bytes
+    }
+}
+
+fn main() {
+    let valid_frame = Frame {
+        txflag: 1,
+        msgtype: 2,
+        sender: 3,
+        route_len: 2,
+        route: vec![9, 9],
+        payload: vec![42, 42, 42],
+    };
+    let valid_bytes = valid_frame.to_bytes();
+
+    for (name, bytes) in [
+        ("empty", &[][..]),
+        ("short", &[1, 2, 3][..]),
+        ("valid", &valid_bytes[..]),
+    ] {
+        println!("Parsing {name} input: {:?}", bytes);
+
+        match Frame::from_bytes(bytes) {
+            Ok(frame) => {
+                println!("Parsed: {:?}", frame);
+                assert_eq!(frame.to_bytes(), bytes, "to_bytes did not round-trip");
+                println!("Round-trip OK");
+            }
+            Err(error) => println!("Failed to parse: {error}"),
+        }
+
+        println!();
+    }
+}
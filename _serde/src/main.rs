@@ -6,18 +6,27 @@
     Make sure to add serde with the derive feature to your Cargo.toml file:
     cargo add serde --features derive
 
-    And, for json support:
+    And, for format support:
     cargo add serde_json
+    cargo add toml
+
+    The original serde_yaml crate was deprecated by its maintainer in 2024, so this uses
+    serde_yaml_ng, a maintained fork with the same API:
+    cargo add serde_yaml_ng
 
     Supported formats:
     https://serde.rs/#data-formats
 */
 
-use std::{fs::File, path::Path, time};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time,
+};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 enum Color {
     Red,
     Green,
@@ -25,57 +34,122 @@ enum Color {
     RgbColor(u8, u8, u8),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 struct Config {
     some_setting: String,
     color: Color,
     enabled_features: Vec<String>,
 }
 
+// Since the same Config can round-trip through any of these, the format is just a
+// matter of which backend serde hands the struct to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl ConfigFormat {
+    fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(ConfigFormat::Json),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "toml" => Some(ConfigFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+fn detect_format(path: &Path) -> Option<ConfigFormat> {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(ConfigFormat::from_extension)
+}
+
 fn main() {
     let now = time::Instant::now();
 
-    let path = Path::new("./config.json");
-    let exists = path.exists();
-
-    let file = match exists {
-        true => match File::open(path) {
-            Ok(file) => file,
-            Err(error) => panic!("Problem opening the config file: {:?}", error),
-        },
-        false => match File::create(path) {
-            Ok(file) => file,
-            Err(error) => panic!("Problem creating the config file: {:?}", error),
-        },
-    };
+    let paths = [
+        PathBuf::from("./config.json"),
+        PathBuf::from("./config.yaml"),
+        PathBuf::from("./config.toml"),
+    ];
+
+    for path in paths {
+        let format = detect_format(&path)
+            .unwrap_or_else(|| panic!("Unsupported config format: {}", path.display()));
 
-    let config = match exists {
-        true => load_config(&file),
-        false => generate_default_config(&file),
+        let config = match path.exists() {
+            true => load_config(&path, format),
+            false => generate_default_config(&path, format),
+        };
+
+        println!("Config loaded from {}:\n{:#?}", path.display(), config);
+    }
+
+    println!();
+
+    // Same Config, three formats, same data in and out.
+    let config = Config {
+        some_setting: String::from("some value"),
+        color: Color::RgbColor(0, 0, 0),
+        enabled_features: vec![String::from("feature1"), String::from("feature2")],
     };
 
-    println!("Current config:\n{:#?}", config);
+    for format in [ConfigFormat::Json, ConfigFormat::Yaml, ConfigFormat::Toml] {
+        let serialized = serialize(&config, format);
+        let deserialized = deserialize(&serialized, format);
+        assert_eq!(config, deserialized, "{:?} round-trip mismatch", format);
+        println!("{:?} round-trip OK", format);
+    }
+
     println!("Elapsed time: {:.2?}", now.elapsed());
 }
 
-fn load_config(file: &File) -> Config {
-    match serde_json::from_reader(file) {
-        Ok(config) => config,
-        Err(error) => panic!("Problem parsing the file: {:?}", error),
-    }
+fn load_config(path: &Path, format: ConfigFormat) -> Config {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) => panic!("Problem reading the config file: {:?}", error),
+    };
+
+    deserialize(&contents, format)
 }
 
-fn generate_default_config(file: &File) -> Config {
+fn generate_default_config(path: &Path, format: ConfigFormat) -> Config {
     let config = Config {
         some_setting: String::from("some value"),
         color: Color::RgbColor(0, 0, 0),
         enabled_features: vec![String::from("feature1"), String::from("feature2")],
     };
 
-    match serde_json::to_writer_pretty(file, &config) {
+    let serialized = serialize(&config, format);
+    match fs::write(path, serialized) {
         Ok(_) => (),
         Err(error) => panic!("Problem writing the file: {:?}", error),
     }
 
     config
 }
+
+fn serialize(config: &Config, format: ConfigFormat) -> String {
+    match format {
+        ConfigFormat::Json => serde_json::to_string_pretty(config)
+            .unwrap_or_else(|error| panic!("Problem serializing the config to JSON: {:?}", error)),
+        ConfigFormat::Yaml => serde_yaml_ng::to_string(config)
+            .unwrap_or_else(|error| panic!("Problem serializing the config to YAML: {:?}", error)),
+        ConfigFormat::Toml => toml::to_string_pretty(config)
+            .unwrap_or_else(|error| panic!("Problem serializing the config to TOML: {:?}", error)),
+    }
+}
+
+fn deserialize(contents: &str, format: ConfigFormat) -> Config {
+    match format {
+        ConfigFormat::Json => serde_json::from_str(contents)
+            .unwrap_or_else(|error| panic!("Problem parsing the JSON config: {:?}", error)),
+        ConfigFormat::Yaml => serde_yaml_ng::from_str(contents)
+            .unwrap_or_else(|error| panic!("Problem parsing the YAML config: {:?}", error)),
+        ConfigFormat::Toml => toml::from_str(contents)
+            .unwrap_or_else(|error| panic!("Problem parsing the TOML config: {:?}", error)),
+    }
+}
@@ -121,3 +121,35 @@ fn _better_function2_that_may_fail() -> Result<(), BetterErrorB> {
 
     For the dynamic approach, check out the _anyhow directory.
 */
+
+use std::path::{Path, PathBuf};
+
+/*
+    A bare std::io::Error only tells you what went wrong, not which file it was about.
+    If _read_file returns plain io::Error, the caller has to remember the path on the side
+    to produce a useful message. Wrapping it in a variant that also stores the PathBuf
+    lets the error format itself: "/not/there: No such file or directory (os error 2)".
+*/
+#[derive(Error, Debug)]
+enum _ConfigError {
+    #[error("{path}: {source}")]
+    Io {
+        #[source]
+        source: std::io::Error,
+        path: PathBuf,
+    },
+}
+
+fn _read_file(path: &Path) -> Result<String, _ConfigError> {
+    std::fs::read_to_string(path).map_err(|source| _ConfigError::Io {
+        source,
+        path: path.to_path_buf(),
+    })
+}
+
+// _print_read_file_error(Path::new("/not/there")) prints: "/not/there: No such file or directory (os error 2)"
+fn _print_read_file_error(path: &Path) {
+    if let Err(error) = _read_file(path) {
+        println!("{error}");
+    }
+}
@@ -12,11 +12,15 @@
     cargo add tokio --features full
 
     You can disable features that you don't need later on by editing your Cargo.toml file.
+
+    The CPU-bound example below also uses Rayon, see the _rayon directory:
+    cargo add rayon
 */
 
-use std::time::Duration;
+use std::{collections::HashMap, ops::Range, time::Duration};
 
-use tokio::{join, select, time::sleep};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use tokio::{join, select, sync::oneshot, time::sleep};
 
 #[tokio::main] // Add the tokio::main attribute to the main function and mark it as async.
 async fn main() {
@@ -91,3 +95,118 @@ async fn awaiting_function() {
 async fn say_hi() {
     println!("Hi");
 }
+
+/*
+    Real services need to shut down cleanly: stop accepting new work, let in-flight work
+    finish, then exit. The common pattern is to race a worker loop against one or more
+    shutdown signals inside select!, rather than checking a flag between iterations.
+
+    tokio::signal::ctrl_c() resolves when the process receives SIGINT (Ctrl+C).
+    A tokio::sync::oneshot channel lets other code in the same process trigger shutdown too.
+
+    Critically, both shutdown sources are awaited inside select!, not polled with try_recv()
+    in a loop. select! registers each branch's waker with the runtime, so the task is woken
+    exactly when a signal arrives. A hand-rolled loop that calls try_recv() every iteration
+    (busy-polling) wastes CPU and can miss the wake-up entirely, since nothing ever registers
+    a waker for the runtime to fire.
+
+    Note that select! drops whichever branches don't win, so the tick future itself must be
+    owned outside the select! to survive that: it's created once per iteration and polled by
+    reference, so if a shutdown signal wins instead, the tick keeps running rather than being
+    cancelled. That's what lets the final await below actually finish the in-flight tick,
+    instead of starting a brand-new one.
+*/
+async fn _run_until_shutdown(mut shutdown_rx: oneshot::Receiver<()>) {
+    let mut ticks = 0;
+    let mut in_flight_tick = Box::pin(_worker_tick());
+
+    loop {
+        select! {
+            _ = &mut in_flight_tick => {
+                ticks += 1;
+                println!("Worker tick {ticks}");
+                in_flight_tick = Box::pin(_worker_tick());
+            }
+            _ = tokio::signal::ctrl_c() => {
+                println!("Received Ctrl+C, shutting down...");
+                break;
+            }
+            _ = &mut shutdown_rx => {
+                println!("Received shutdown signal, shutting down...");
+                break;
+            }
+        }
+    }
+
+    println!("Awaiting in-flight work before exiting...");
+    in_flight_tick.await;
+    println!("Shutdown complete after {ticks} ticks");
+}
+
+async fn _worker_tick() {
+    sleep(Duration::from_millis(500)).await;
+}
+
+// Other code can trigger a graceful shutdown programmatically, e.g.:
+// let (shutdown_tx, shutdown_rx) = oneshot::channel();
+// tokio::spawn(_run_until_shutdown(shutdown_rx));
+// shutdown_tx.send(()).unwrap();
+
+/*
+    CPU-bound work blocks the async reactor if awaited directly, since tokio's worker
+    threads are meant to keep making progress on other tasks while waiting on I/O.
+    tokio::task::spawn_blocking moves the closure onto a dedicated blocking thread pool,
+    so the Rayon thread pool can do the actual CPU work without starving the reactor.
+*/
+async fn _compute_fibonacci_range(range: Range<u64>) -> HashMap<u64, u64> {
+    tokio::task::spawn_blocking(move || _rayon_parallel_fibonacci(range))
+        .await
+        .expect("blocking task panicked")
+}
+
+fn _rayon_parallel_fibonacci(range: Range<u64>) -> HashMap<u64, u64> {
+    range
+        .into_par_iter()
+        .map(|index| (index, _fibonacci(index)))
+        .collect()
+}
+
+fn _fibonacci(n: u64) -> u64 {
+    match n {
+        0 => 0,
+        1 => 1,
+        _ => _fibonacci(n - 1) + _fibonacci(n - 2),
+    }
+}
+
+/*
+    As an alternative pattern: when a result comes from an arbitrary OS thread instead of
+    tokio's blocking pool (e.g. a callback-driven library), a oneshot channel turns its
+    completion into something awaitable. The spawned thread does its work and sends the
+    result; the async side just awaits the receiver.
+*/
+async fn _compute_fibonacci_range_on_thread(range: Range<u64>) -> HashMap<u64, u64> {
+    let (tx, rx) = oneshot::channel();
+
+    std::thread::spawn(move || {
+        let result = _rayon_parallel_fibonacci(range);
+        let _ = tx.send(result);
+    });
+
+    rx.await.expect("worker thread dropped the sender")
+}
+
+// Timing comparison between the blocked-inline version and the offloaded versions:
+//
+// let range = 0..40;
+// let now = Instant::now();
+// _rayon_parallel_fibonacci(range.clone()); // runs inline, blocks the current thread
+// println!("Inline: {:?}", now.elapsed());
+//
+// let now = Instant::now();
+// _compute_fibonacci_range(range.clone()).await;
+// println!("spawn_blocking: {:?}", now.elapsed());
+//
+// let now = Instant::now();
+// _compute_fibonacci_range_on_thread(range).await;
+// println!("oneshot + thread: {:?}", now.elapsed());